@@ -19,9 +19,21 @@
 //! backed by an immutable balanced binary tree. The Vec-backed sets in this
 //! module may benefit from better cache efficiency, and so may outperform the
 //! balanced tree implementation in some circumstances.
+//!
+//! Lookups in `MultiSet`/`SortedSet` are linear/logarithmic, and the
+//! balanced-tree `Set` is pointer-chasing. For large, build-once-then-read
+//! sets with heavy membership testing, this module also provides `HashSet`:
+//! an immutable hash-array-mapped-trie (HAMT) set, also arena-allocated,
+//! whose `contains` is effectively O(1).
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::convert::From;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Bound;
+use std::ops::RangeBounds;
 
 use bumpalo::Bump;
 use serde::Serialize;
@@ -129,6 +141,31 @@ impl<'a, T: 'a> MultiSet<'a, T> {
         self.list.is_empty()
     }
 
+    /// Returns the element at insertion-order index `i`, or `None` if
+    /// `i >= self.len()`.
+    ///
+    /// Since the set is backed by a contiguous `Vec`, this is an O(1)
+    /// access, unlike the pointer-chasing required by a balanced-tree set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSet, MultiSetMut};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// set.insert(3);
+    /// set.insert(1);
+    /// let set = MultiSet::from(set);
+    /// assert_eq!(set.get_index(0), Some(&3));
+    /// assert_eq!(set.get_index(1), Some(&1));
+    /// assert_eq!(set.get_index(2), None);
+    /// ```
+    pub fn get_index(&self, i: usize) -> Option<&T> {
+        self.list.as_slice().get(i).map(|(k, _)| k)
+    }
+
     /// Make a new `MultiSet` containing the values in the given slice.
     ///
     /// Provided for the sake of creating empty const sets. Passing non-empty
@@ -398,6 +435,73 @@ impl<'a, T> SortedSet<'a, T> {
         self.list.contains_key(value)
     }
 
+    /// Returns the element at sorted-order index `i`, or `None` if
+    /// `i >= self.len()`.
+    ///
+    /// Since the set is backed by a contiguous sorted `Vec`, this is an O(1)
+    /// access, unlike the pointer-chasing required by a balanced-tree set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// set.insert(3);
+    /// set.insert(1);
+    /// let set = SortedSet::from(set);
+    /// assert_eq!(set.get_index(0), Some(&1));
+    /// assert_eq!(set.get_index(1), Some(&3));
+    /// assert_eq!(set.get_index(2), None);
+    /// ```
+    pub fn get_index(&self, i: usize) -> Option<&T> {
+        self.list.as_slice().get(i).map(|(k, _)| k)
+    }
+
+    /// Returns the index of `value` via binary search over the sorted key
+    /// slice: `Ok(i)` if `self.get_index(i) == Some(value)`, or `Err(i)` for
+    /// the index at which `value` would need to be inserted to keep the set
+    /// sorted.
+    ///
+    /// This gives callers order-statistic access (e.g. the median or quorum
+    /// element of a sorted id set) in `O(log n)` without re-iterating the
+    /// whole set, and a stable numeric reference to a given member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// for i in [1, 3, 5] {
+    ///     set.insert(i);
+    /// }
+    /// let set = SortedSet::from(set);
+    /// assert_eq!(set.rank(&3), Ok(1));
+    /// assert_eq!(set.rank(&4), Err(2));
+    /// ```
+    pub fn rank<Q: ?Sized>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord,
+    {
+        let mut lo = 0;
+        let mut hi = self.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.get_index(mid).unwrap().borrow().cmp(value) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+
     /// Get an iterator over the elements of the set in ascending order.
     ///
     /// # Examples
@@ -463,6 +567,138 @@ impl<'a, T> SortedSet<'a, T> {
         self.list.is_empty()
     }
 
+    /// Returns the first (smallest) element in the set, or `None` if it is
+    /// empty.
+    ///
+    /// Since the backing list is a contiguous sorted slice, this is an O(1)
+    /// slice-start access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// set.insert(3);
+    /// set.insert(1);
+    /// let set = SortedSet::from(set);
+    /// assert_eq!(set.first(), Some(&1));
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self.list.as_slice().first().map(|(k, _)| k)
+    }
+
+    /// Returns the last (largest) element in the set, or `None` if it is
+    /// empty.
+    ///
+    /// Since the backing list is a contiguous sorted slice, this is an O(1)
+    /// slice-end access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// set.insert(3);
+    /// set.insert(1);
+    /// let set = SortedSet::from(set);
+    /// assert_eq!(set.last(), Some(&3));
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        self.list.as_slice().last().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the elements of the set within `range`, in
+    /// ascending order.
+    ///
+    /// Implemented with two binary searches (one per bound) over the sorted
+    /// key slice, so the only per-call cost beyond the returned iterator
+    /// itself is `O(log n)` — unlike `iter().filter(..)`, which would scan
+    /// every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = MultiSetMut::new_in(&b);
+    /// for i in 0..10 {
+    ///     set.insert(i);
+    /// }
+    /// let set = SortedSet::from(set);
+    /// assert_eq!(set.range(3..6).collect::<Vec<_>>(), vec![&3, &4, &5]);
+    /// ```
+    pub fn range<Q: ?Sized, R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        T: Borrow<Q>,
+        Q: Ord,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(q) => self.lower_bound(q),
+            Bound::Excluded(q) => self.upper_bound(q),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(q) => self.upper_bound(q),
+            Bound::Excluded(q) => self.lower_bound(q),
+            Bound::Unbounded => self.len(),
+        };
+        let end = end.max(start);
+        self.list.as_slice()[start..end].iter().map(|(k, _)| k)
+    }
+
+    /// Returns the index of the first element that is `>= value`, via binary
+    /// search over the sorted key slice. Equal to `self.len()` if every
+    /// element is smaller.
+    fn lower_bound<Q: ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord,
+    {
+        let slice = self.list.as_slice();
+        let mut lo = 0;
+        let mut hi = slice.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if slice[mid].0.borrow() < value {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Returns the index of the first element that is `> value`, via binary
+    /// search over the sorted key slice. Equal to `self.len()` if every
+    /// element is smaller or equal.
+    fn upper_bound<Q: ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord,
+    {
+        let slice = self.list.as_slice();
+        let mut lo = 0;
+        let mut hi = slice.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if slice[mid].0.borrow() <= value {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
     /// Make a new `SortedSet` containing the values in the given slice.
     ///
     /// Provided for the sake of creating empty const sets. Passing non-empty
@@ -485,6 +721,331 @@ impl<'a, T> SortedSet<'a, T> {
             list: SortedAssocList::from_slice(list),
         }
     }
+
+    /// Returns a new set containing the elements present in either `self` or
+    /// `other`, allocated in `bump`.
+    ///
+    /// Runs in `O(self.len() + other.len())`: a single linear merge over the
+    /// two sorted key slices, emitting the smaller of the two cursors at each
+    /// step (one copy, advancing both cursors, on a tie). Like [`SortedSet::from_slice`], the
+    /// result is built directly from an already-sorted, already-deduplicated
+    /// slice, so no post-hoc sort or dedup pass is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut a = MultiSetMut::new_in(&b);
+    /// a.insert(1);
+    /// a.insert(2);
+    /// let a = SortedSet::from(a);
+    /// let mut b_set = MultiSetMut::new_in(&b);
+    /// b_set.insert(2);
+    /// b_set.insert(3);
+    /// let b_set = SortedSet::from(b_set);
+    /// let u = a.union(&b_set, &b);
+    /// assert_eq!(u.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn union<'b, 'c>(&self, other: &SortedSet<'b, T>, bump: &'c Bump) -> SortedSet<'c, T>
+    where
+        T: Ord + Clone,
+    {
+        let mut result =
+            bumpalo::collections::Vec::with_capacity_in(self.len() + other.len(), bump);
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        result.push((x.clone(), ()));
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        result.push((y.clone(), ()));
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        result.push((x.clone(), ()));
+                        a.next();
+                        b.next();
+                    }
+                },
+                (Some(&x), None) => {
+                    result.push((x.clone(), ()));
+                    a.next();
+                }
+                (None, Some(&y)) => {
+                    result.push((y.clone(), ()));
+                    b.next();
+                }
+                (None, None) => break,
+            }
+        }
+        SortedSet {
+            list: SortedAssocList::from_slice(result.into_bump_slice()),
+        }
+    }
+
+    /// Returns a new set containing the elements present in both `self` and
+    /// `other`, allocated in `bump`.
+    ///
+    /// Runs in `O(self.len() + other.len())` via the same two-pointer merge
+    /// as [`SortedSet::union`], emitting an element only when both cursors
+    /// agree.
+    pub fn intersection<'b, 'c>(&self, other: &SortedSet<'b, T>, bump: &'c Bump) -> SortedSet<'c, T>
+    where
+        T: Ord + Clone,
+    {
+        let mut result = bumpalo::collections::Vec::new_in(bump);
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        result.push((x.clone(), ()));
+                        a.next();
+                        b.next();
+                    }
+                },
+                _ => break,
+            }
+        }
+        SortedSet {
+            list: SortedAssocList::from_slice(result.into_bump_slice()),
+        }
+    }
+
+    /// Returns a new set containing the elements present in `self` but not in
+    /// `other`, allocated in `bump`.
+    ///
+    /// Runs in `O(self.len() + other.len())` via the same two-pointer merge
+    /// as [`SortedSet::union`], emitting only elements where `self`'s cursor
+    /// is strictly behind `other`'s.
+    pub fn difference<'b, 'c>(&self, other: &SortedSet<'b, T>, bump: &'c Bump) -> SortedSet<'c, T>
+    where
+        T: Ord + Clone,
+    {
+        let mut result = bumpalo::collections::Vec::with_capacity_in(self.len(), bump);
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        result.push((x.clone(), ()));
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        a.next();
+                        b.next();
+                    }
+                },
+                (Some(&x), None) => {
+                    result.push((x.clone(), ()));
+                    a.next();
+                }
+                _ => break,
+            }
+        }
+        SortedSet {
+            list: SortedAssocList::from_slice(result.into_bump_slice()),
+        }
+    }
+
+    /// Returns a new set containing the elements present in exactly one of
+    /// `self` or `other`, allocated in `bump`.
+    ///
+    /// Runs in `O(self.len() + other.len())` via the same two-pointer merge
+    /// as [`SortedSet::union`], emitting whichever cursor is behind and
+    /// skipping both on a tie.
+    pub fn symmetric_difference<'b, 'c>(
+        &self,
+        other: &SortedSet<'b, T>,
+        bump: &'c Bump,
+    ) -> SortedSet<'c, T>
+    where
+        T: Ord + Clone,
+    {
+        let mut result =
+            bumpalo::collections::Vec::with_capacity_in(self.len() + other.len(), bump);
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        result.push((x.clone(), ()));
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        result.push((y.clone(), ()));
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        a.next();
+                        b.next();
+                    }
+                },
+                (Some(&x), None) => {
+                    result.push((x.clone(), ()));
+                    a.next();
+                }
+                (None, Some(&y)) => {
+                    result.push((y.clone(), ()));
+                    b.next();
+                }
+                (None, None) => break,
+            }
+        }
+        SortedSet {
+            list: SortedAssocList::from_slice(result.into_bump_slice()),
+        }
+    }
+
+    /// Returns a lazy iterator over the structural differences between
+    /// `self` and `other`: a `DiffItem::Remove` for each element only in
+    /// `self`, and a `DiffItem::Add` for each element only in `other`.
+    ///
+    /// Implemented as a merge walk holding a cursor into each set's sorted
+    /// key slice: whichever side compares less is emitted and advanced,
+    /// equal elements are skipped on both sides, and once one side is
+    /// exhausted the rest of the other is drained. This is linear in the
+    /// combined size and never materializes an intermediate set, which is
+    /// useful for incrementally recomputing exactly what changed between two
+    /// compilation passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{DiffItem, MultiSetMut, SortedSet};
+    ///
+    /// let b = Bump::new();
+    /// let mut a = MultiSetMut::new_in(&b);
+    /// a.insert(1);
+    /// a.insert(2);
+    /// let a = SortedSet::from(a);
+    /// let mut c = MultiSetMut::new_in(&b);
+    /// c.insert(2);
+    /// c.insert(3);
+    /// let c = SortedSet::from(c);
+    /// let diffs: Vec<_> = a.diff(&c).collect();
+    /// assert_eq!(diffs, vec![DiffItem::Remove(&1), DiffItem::Add(&3)]);
+    /// ```
+    pub fn diff<'s, 'b>(
+        &'s self,
+        other: &'s SortedSet<'b, T>,
+    ) -> impl Iterator<Item = DiffItem<'s, T>>
+    where
+        T: Ord,
+    {
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        std::iter::from_fn(move || loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => return Some(DiffItem::Remove(a.next().unwrap())),
+                    Ordering::Greater => return Some(DiffItem::Add(b.next().unwrap())),
+                    Ordering::Equal => {
+                        a.next();
+                        b.next();
+                    }
+                },
+                (Some(_), None) => return Some(DiffItem::Remove(a.next().unwrap())),
+                (None, Some(_)) => return Some(DiffItem::Add(b.next().unwrap())),
+                (None, None) => return None,
+            }
+        })
+    }
+
+    /// Returns `true` if every element of `self` is also in `other`.
+    ///
+    /// Runs a single linear merge over the two sorted key slices rather than
+    /// calling `contains` for each element (which would be `O(n log m)`):
+    /// fails fast the moment an element of `self` is strictly smaller than
+    /// the current element of `other`. Bails out immediately if
+    /// `self.len() > other.len()`, since a larger set can never be a subset
+    /// of a smaller one.
+    pub fn is_subset<'b>(&self, other: &SortedSet<'b, T>) -> bool
+    where
+        T: Ord,
+    {
+        if self.len() > other.len() {
+            return false;
+        }
+        let mut other_iter = other.iter();
+        'self_elems: for x in self.iter() {
+            for y in other_iter.by_ref() {
+                match x.cmp(y) {
+                    Ordering::Less => return false,
+                    Ordering::Equal => continue 'self_elems,
+                    Ordering::Greater => continue,
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Returns `true` if every element of `other` is also in `self`.
+    ///
+    /// Delegates to [`SortedSet::is_subset`] with the arguments swapped.
+    pub fn is_superset<'b>(&self, other: &SortedSet<'b, T>) -> bool
+    where
+        T: Ord,
+    {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no elements in common.
+    ///
+    /// Runs a single linear merge over the two sorted key slices, failing
+    /// fast on the first equal pair.
+    pub fn is_disjoint<'b>(&self, other: &SortedSet<'b, T>) -> bool
+    where
+        T: Ord,
+    {
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(&x), Some(&y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        b.next();
+                    }
+                    Ordering::Equal => return false,
+                },
+                _ => return true,
+            }
+        }
+    }
+}
+
+/// A single structural difference between two `SortedSet`s, as produced by
+/// [`SortedSet::diff`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DiffItem<'a, T> {
+    /// The element is present in the right-hand set but not the left.
+    Add(&'a T),
+    /// The element is present in the left-hand set but not the right.
+    Remove(&'a T),
 }
 
 impl<'a, T: Ord> From<MultiSetMut<'a, T>> for SortedSet<'a, T> {
@@ -494,4 +1055,407 @@ impl<'a, T: Ord> From<MultiSetMut<'a, T>> for SortedSet<'a, T> {
             list: set.list.into(),
         }
     }
-}
\ No newline at end of file
+}
+
+/// The branching factor of the `HashSet` trie: each level of the trie
+/// dispatches on a 5-bit chunk of the hash, giving 32-way fan-out.
+const HASH_SET_BITS: u32 = 5;
+const HASH_SET_MASK: u64 = (1 << HASH_SET_BITS) - 1;
+
+/// A trie node, either a leaf holding the (rare) hash-collision bucket for a
+/// single hash, or a branch whose present children are packed densely via a
+/// bitmap + popcount (so a 32-way branch with only a few live children costs
+/// only as many words as it has children, not 32).
+#[derive(Serialize)]
+enum HashTrieNode<'a, T> {
+    Leaf {
+        hash: u64,
+        items: &'a [T],
+    },
+    Branch {
+        bitmap: u32,
+        children: &'a [HashTrieNode<'a, T>],
+    },
+}
+
+impl<'a, T> HashTrieNode<'a, T> {
+    fn contains<Q: ?Sized>(&self, hash: u64, shift: u32, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq,
+    {
+        match self {
+            HashTrieNode::Leaf { hash: h, items } => {
+                *h == hash && items.iter().any(|item| item.borrow() == value)
+            }
+            HashTrieNode::Branch { bitmap, children } => {
+                let chunk = ((hash >> shift) & HASH_SET_MASK) as u32;
+                let bit = 1u32 << chunk;
+                if bitmap & bit == 0 {
+                    false
+                } else {
+                    let idx = (bitmap & (bit - 1)).count_ones() as usize;
+                    children[idx].contains(hash, shift + HASH_SET_BITS, value)
+                }
+            }
+        }
+    }
+
+    fn iter(&'a self) -> HashSetIter<'a, T> {
+        HashSetIter {
+            branches: vec![],
+            leaf: [].iter(),
+            next: Some(self),
+        }
+    }
+}
+
+/// A readonly arena-backed hash set, built as a hash-array-mapped trie
+/// (HAMT), as in `im`'s HAMT set.
+///
+/// * `contains` is effectively O(1), even for large sets, unlike the O(log
+///   n) `SortedSet` or the pointer-chasing balanced-tree `Set`
+/// * Like the other readonly array-backed sets, it is built once and never
+///   modified thereafter; it is built only from a `HashSetMut` (there is no
+///   conversion from a `MultiSetMut` or from an iterator)
+#[derive(Serialize)]
+pub struct HashSet<'a, T: 'a> {
+    root: &'a HashTrieNode<'a, T>,
+    len: usize,
+}
+
+impl<T> Copy for HashSet<'_, T> {}
+impl<T> Clone for HashSet<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> HashSet<'a, T> {
+    /// Returns `true` if the set contains a value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but the
+    /// hash and equality of the borrowed form *must* match that of the value
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{HashSet, HashSetMut};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = HashSetMut::new_in(&b);
+    /// set.insert(1);
+    /// let set = HashSet::from(set);
+    /// assert!(set.contains(&1));
+    /// assert!(!set.contains(&2));
+    /// ```
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.root.contains(hash_of(value), 0, value)
+    }
+
+    /// Get an iterator over the elements of the set, in unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::{HashSet, HashSetMut};
+    ///
+    /// let b = Bump::new();
+    /// let mut set = HashSetMut::new_in(&b);
+    /// set.insert(1);
+    /// set.insert(2);
+    /// let set = HashSet::from(set);
+    /// let mut elems: Vec<_> = set.iter().collect();
+    /// elems.sort();
+    /// assert_eq!(elems, vec![&1, &2]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.root.iter()
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Make a new, empty `HashSet`.
+    ///
+    /// Provided for the sake of creating empty const sets. The slice is
+    /// ignored; this constructor cannot build a non-empty trie at compile
+    /// time, so passing a non-empty slice is not recommended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arena_collections::HashSet;
+    ///
+    /// const EMPTY_HASH_SET: HashSet<'_, i32> = HashSet::from_slice(&[]);
+    /// assert!(EMPTY_HASH_SET.is_empty());
+    /// ```
+    pub const fn from_slice(_slice: &'a [T]) -> Self {
+        Self {
+            root: &HashTrieNode::Branch {
+                bitmap: 0,
+                children: &[],
+            },
+            len: 0,
+        }
+    }
+}
+
+/// An iterator over the elements of a [`HashSet`], in unspecified order.
+///
+/// Walks the trie depth-first using an explicit stack of sibling-iterators,
+/// rather than recursion, since the trie's depth is data-dependent.
+pub struct HashSetIter<'a, T> {
+    branches: Vec<std::slice::Iter<'a, HashTrieNode<'a, T>>>,
+    leaf: std::slice::Iter<'a, T>,
+    next: Option<&'a HashTrieNode<'a, T>>,
+}
+
+impl<'a, T> Iterator for HashSetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(node) = self.next.take() {
+                match node {
+                    HashTrieNode::Leaf { items, .. } => self.leaf = items.iter(),
+                    HashTrieNode::Branch { children, .. } => {
+                        self.branches.push(children.iter());
+                    }
+                }
+            }
+            if let Some(item) = self.leaf.next() {
+                return Some(item);
+            }
+            match self.branches.last_mut() {
+                Some(siblings) => match siblings.next() {
+                    Some(child) => self.next = Some(child),
+                    None => {
+                        self.branches.pop();
+                    }
+                },
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A mutable, pre-arena builder for [`HashSet`], used only while inserting
+/// elements. The trie is assembled with ordinary heap allocations (so that
+/// splitting a leaf when a second, different hash lands in it doesn't require mutating
+/// already-allocated arena memory), and copied into the arena all at once
+/// when frozen into a `HashSet`.
+enum BuildNode<T> {
+    Leaf {
+        hash: u64,
+        items: Vec<T>,
+    },
+    Branch {
+        bitmap: u32,
+        children: Vec<BuildNode<T>>,
+    },
+}
+
+impl<T: Hash + Eq> BuildNode<T> {
+    fn empty_branch() -> Self {
+        BuildNode::Branch {
+            bitmap: 0,
+            children: Vec::new(),
+        }
+    }
+
+    fn contains<Q: ?Sized>(&self, hash: u64, shift: u32, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq,
+    {
+        match self {
+            BuildNode::Leaf { hash: h, items } => {
+                *h == hash && items.iter().any(|item| item.borrow() == value)
+            }
+            BuildNode::Branch { bitmap, children } => {
+                let chunk = ((hash >> shift) & HASH_SET_MASK) as u32;
+                let bit = 1u32 << chunk;
+                if bitmap & bit == 0 {
+                    false
+                } else {
+                    let idx = (bitmap & (bit - 1)).count_ones() as usize;
+                    children[idx].contains(hash, shift + HASH_SET_BITS, value)
+                }
+            }
+        }
+    }
+
+    /// Inserts `value` (with precomputed `hash`), returning `true` if it was
+    /// not already present.
+    fn insert(&mut self, hash: u64, shift: u32, value: T) -> bool {
+        match self {
+            BuildNode::Leaf {
+                hash: leaf_hash,
+                items,
+            } => {
+                if *leaf_hash == hash {
+                    if items.contains(&value) {
+                        false
+                    } else {
+                        items.push(value);
+                        true
+                    }
+                } else {
+                    // Two different hashes landed in the same leaf; split it
+                    // into a branch and push both down another level.
+                    let old_hash = *leaf_hash;
+                    let old_items = std::mem::take(items);
+                    let mut branch = BuildNode::empty_branch();
+                    for item in old_items {
+                        branch.insert(old_hash, shift, item);
+                    }
+                    let inserted = branch.insert(hash, shift, value);
+                    *self = branch;
+                    inserted
+                }
+            }
+            BuildNode::Branch { bitmap, children } => {
+                let chunk = ((hash >> shift) & HASH_SET_MASK) as u32;
+                let bit = 1u32 << chunk;
+                let idx = (*bitmap & (bit - 1)).count_ones() as usize;
+                if *bitmap & bit == 0 {
+                    children.insert(
+                        idx,
+                        BuildNode::Leaf {
+                            hash,
+                            items: vec![value],
+                        },
+                    );
+                    *bitmap |= bit;
+                    true
+                } else {
+                    children[idx].insert(hash, shift + HASH_SET_BITS, value)
+                }
+            }
+        }
+    }
+
+    fn freeze_in<'bump>(self, bump: &'bump Bump) -> HashTrieNode<'bump, T> {
+        match self {
+            BuildNode::Leaf { hash, items } => HashTrieNode::Leaf {
+                hash,
+                items: bump.alloc_slice_fill_iter(items),
+            },
+            BuildNode::Branch { bitmap, children } => {
+                let mut frozen = bumpalo::collections::Vec::with_capacity_in(children.len(), bump);
+                for child in children {
+                    frozen.push(child.freeze_in(bump));
+                }
+                HashTrieNode::Branch {
+                    bitmap,
+                    children: frozen.into_bump_slice(),
+                }
+            }
+        }
+    }
+}
+
+fn hash_of<Q: Hash + ?Sized>(value: &Q) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A mutable hash set, built as a hash-array-mapped trie (HAMT) on the heap
+/// and copied into its arena when frozen into a [`HashSet`].
+///
+/// * Lookups run in effectively constant time
+/// * Insertions run in effectively constant time
+/// * Duplicate elements are not permitted
+pub struct HashSetMut<'bump, T> {
+    bump: &'bump Bump,
+    root: BuildNode<T>,
+    len: usize,
+}
+
+impl<'bump, T: Hash + Eq> HashSetMut<'bump, T> {
+    /// Constructs a new, empty `HashSetMut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::HashSetMut;
+    ///
+    /// let b = Bump::new();
+    /// let mut set: HashSetMut<i32> = HashSetMut::new_in(&b);
+    /// ```
+    #[inline]
+    pub fn new_in(bump: &'bump Bump) -> Self {
+        HashSetMut {
+            bump,
+            root: BuildNode::empty_branch(),
+            len: 0,
+        }
+    }
+
+    /// Returns `true` if the set contains a value.
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.root.contains(hash_of(value), 0, value)
+    }
+
+    /// Add a value to the set. Returns `true` if the value was not already
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use arena_collections::HashSetMut;
+    ///
+    /// let b = Bump::new();
+    /// let mut set = HashSetMut::new_in(&b);
+    /// assert_eq!(set.insert(1), true);
+    /// assert_eq!(set.insert(1), false);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let hash = hash_of(&value);
+        let inserted = self.root.insert(hash, 0, value);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'bump, T: Hash + Eq> From<HashSetMut<'bump, T>> for HashSet<'bump, T> {
+    #[inline]
+    fn from(set: HashSetMut<'bump, T>) -> Self {
+        let root = set.bump.alloc(set.root.freeze_in(set.bump));
+        HashSet { root, len: set.len }
+    }
+}
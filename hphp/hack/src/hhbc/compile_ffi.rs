@@ -42,12 +42,133 @@ impl<'content> FromOcamlRep for OcamlStr<'content> {
     }
 }
 
+/// An in/out error-reporting buffer.
+///
+/// The caller provides `buf`/`buf_len` pointing at scratch space it owns. If
+/// the message written by the FFI call plus its trailing NUL does not fit in
+/// `buf_len` bytes, the
+/// call fails without writing anything and instead updates `buf_len` to the
+/// number of bytes (including the trailing NUL) the caller must allocate
+/// before retrying — the standard C resize-and-retry convention.
 #[repr(C)]
 pub struct CErrBuf {
     pub buf: *mut c_char,
     pub buf_len: c_int,
 }
 
+impl CErrBuf {
+    /// Writes `msg` (plus a trailing NUL) into `self.buf`.
+    ///
+    /// Returns `true` if the message fit. If it did not fit (including a
+    /// negative `self.buf_len`, treated as zero capacity), `self.buf_len` is
+    /// updated to the number of bytes (including the trailing NUL) the
+    /// caller must provide on retry, and `false` is returned; `self.buf` is
+    /// left untouched.
+    ///
+    /// # Safety
+    /// `self.buf` must be valid for reads and writes of
+    /// `self.buf_len * mem::size_of::<u8>()` bytes, when `self.buf_len` is
+    /// non-negative.
+    unsafe fn write(&mut self, msg: &str) -> bool {
+        let needed = msg.len() + 1;
+        if self.buf_len < 0 || needed > self.buf_len as usize {
+            self.buf_len = needed as c_int;
+            return false;
+        }
+        let buf: &mut [u8] =
+            std::slice::from_raw_parts_mut(self.buf as *mut u8, self.buf_len as usize);
+        std::ptr::copy_nonoverlapping(msg.as_ptr(), buf.as_mut_ptr(), msg.len());
+        buf[msg.len()] = 0;
+        true
+    }
+}
+
+/// Outcome classification for a `_cpp_ffi` compile entry point, written back
+/// through the caller's `status` out-param.
+///
+/// Previously a caught panic was indistinguishable from a legitimate empty
+/// result: both surfaced as a null pointer. `InternalPanic` lets a C++
+/// embedding tell "the compiler crashed" apart from "compilation failed
+/// normally" and log/report accordingly.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CCompileStatus {
+    Success,
+    /// Reserved for parity with the OCaml FFI path's `emit_fatal_program`;
+    /// the `_cpp_ffi` entry points below do not currently produce it.
+    FatalProgram,
+    CompileError,
+    InternalPanic,
+    BufferTooSmall,
+    InvalidUtf8,
+}
+
+/// Writes `status` into `*out` if `out` is non-null.
+///
+/// # Safety
+/// `out` must be null or a valid, aligned pointer to a `CCompileStatus`.
+unsafe fn set_status(out: *mut CCompileStatus, status: CCompileStatus) {
+    if let Some(out) = out.as_mut() {
+        *out = status;
+    }
+}
+
+/// Downcasts a `catch_unwind` panic payload to a displayable message,
+/// covering the two payload types the `panic!` family actually produces.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(s) => s,
+        None => match payload.downcast_ref::<String>() {
+            Some(s) => s.as_str(),
+            None => "unknown panic payload",
+        },
+    }
+}
+
+/// Converts a duration in seconds (as reported by `Profile`) to whole
+/// microseconds, matching `print_output`'s `to_microsec` convention.
+fn to_microsec(secs: f64) -> u64 {
+    (secs * 1_000_000.0) as u64
+}
+
+/// Optional per-call profiling data, written back through the caller's
+/// `profile` out-param when non-null. Fields not produced by a given entry
+/// point (e.g. printing time during `_from_text`) are left at zero.
+#[repr(C)]
+pub struct CProfile {
+    pub parsing_us: u64,
+    pub codegen_us: u64,
+    pub printing_us: u64,
+    pub emitted_bytes: u64,
+}
+
+/// Checked counterpart to the `from_utf8_unchecked` calls below, used when
+/// `CNativeEnv::validate_utf8` opts into the safe path. Reports the
+/// offending field name and byte offset through `err_buf` on failure.
+///
+/// # Safety
+/// `ptr` must be a valid, aligned, nul-terminated C string.
+unsafe fn check_utf8(
+    field: &str,
+    ptr: *const c_char,
+    err_buf: &mut CErrBuf,
+) -> Result<(), CCompileStatus> {
+    let bytes = std::ffi::CStr::from_ptr(ptr).to_bytes();
+    if let Err(e) = std::str::from_utf8(bytes) {
+        let msg = format!(
+            "{} is not valid UTF-8 at byte offset {}",
+            field,
+            e.valid_up_to()
+        );
+        return Err(if err_buf.write(&msg) {
+            CCompileStatus::InvalidUtf8
+        } else {
+            CCompileStatus::BufferTooSmall
+        });
+    }
+    Ok(())
+}
+
 #[repr(C)]
 struct CNativeEnv {
     decl_getter:
@@ -61,8 +182,64 @@ struct CNativeEnv {
     hhbc_flags: u32,
     parser_flags: u32,
     flags: u8,
+    /// When non-zero, `aliased_namespaces`, `include_roots`, `config_jsons`,
+    /// and `config_list` are validated as UTF-8 before use instead of
+    /// decoded with `from_utf8_unchecked`.
+    validate_utf8: u8,
+    config_jsons: *const *const c_char,
+    num_config_jsons: usize,
+    config_list: *const *const c_char,
+    num_config_list: usize,
 }
 impl CNativeEnv {
+    /// Reads a C array of `len` nul-terminated C strings (or `[]` if `ptr`
+    /// is null) into a `Vec<String>`, the shape `Env::config_jsons` and
+    /// `Env::config_list` expect.
+    ///
+    /// When `validate` is set, each string is checked with
+    /// `std::str::from_utf8`, reporting `field`, the offending array index,
+    /// and the byte offset through `err_buf` on failure; otherwise entries
+    /// are decoded with `from_utf8_unchecked`.
+    ///
+    /// # Safety
+    /// * `ptr` must be null, or a valid, aligned pointer to `len` valid,
+    ///   aligned, nul-terminated C strings, and (when `validate` is false)
+    ///   containing valid UTF-8.
+    unsafe fn c_str_array_to_vec(
+        ptr: *const *const c_char,
+        len: usize,
+        field: &str,
+        validate: bool,
+        err_buf: &mut CErrBuf,
+    ) -> Result<Vec<String>, CCompileStatus> {
+        if ptr.is_null() {
+            return Ok(Vec::new());
+        }
+        std::slice::from_raw_parts(ptr, len)
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| {
+                let bytes = std::ffi::CStr::from_ptr(s).to_bytes();
+                if !validate {
+                    return Ok(std::str::from_utf8_unchecked(bytes).to_owned());
+                }
+                std::str::from_utf8(bytes).map(str::to_owned).map_err(|e| {
+                    let msg = format!(
+                        "{}[{}] is not valid UTF-8 at byte offset {}",
+                        field,
+                        i,
+                        e.valid_up_to()
+                    );
+                    if err_buf.write(&msg) {
+                        CCompileStatus::InvalidUtf8
+                    } else {
+                        CCompileStatus::BufferTooSmall
+                    }
+                })
+            })
+            .collect()
+    }
+
     /// Returns `None` if `env` is null.
     ///
     /// # Safety
@@ -132,7 +309,9 @@ unsafe extern "C" fn hackc_compile_hhas_from_text_cpp_ffi(
     alloc: *const bumpalo::Bump,
     cnative_env: *const CNativeEnv,
     source_text: *const c_char,
-    err_buf: *const CErrBuf,
+    err_buf: *mut CErrBuf,
+    status: *mut CCompileStatus,
+    profile_out: *mut CProfile,
 ) -> *const HhasProgram<'static> {
     match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         // Safety: `alloc` came via `hackc_compile_hhas_create_arena`.
@@ -141,23 +320,58 @@ unsafe extern "C" fn hackc_compile_hhas_from_text_cpp_ffi(
         // `*const CNativeEnv`.
         let cnative_env: &CNativeEnv = cnative_env.as_ref().unwrap();
         // Safety: `err_buf` is a well aligned, properly initialized
-        // `*const CErrBuf`.
-        let err_buf: &CErrBuf = err_buf.as_ref().unwrap();
-        // Safety : `err_buf.buf` must be valid for reads and writes
-        // for `err_buf.buf_len * mem::sizeof::<u8>()` bytes.
-        let buf: &mut [u8] =
-            std::slice::from_raw_parts_mut(err_buf.buf as *mut u8, err_buf.buf_len as usize);
+        // `*mut CErrBuf`.
+        let err_buf: &mut CErrBuf = err_buf.as_mut().unwrap();
         // Safety: `source_text` is a properly iniitalized
         // nul-terminated C string.
         let text: &[u8] = std::ffi::CStr::from_ptr(source_text).to_bytes();
 
+        let validate = cnative_env.validate_utf8 != 0;
+        if validate {
+            for (field, ptr) in [
+                ("aliased_namespaces", cnative_env.aliased_namespaces),
+                ("include_roots", cnative_env.include_roots),
+            ] {
+                if let Err(status_code) = check_utf8(field, ptr, err_buf) {
+                    set_status(status, status_code);
+                    return std::ptr::null();
+                }
+            }
+        }
+        let config_jsons = match CNativeEnv::c_str_array_to_vec(
+            cnative_env.config_jsons,
+            cnative_env.num_config_jsons,
+            "config_jsons",
+            validate,
+            err_buf,
+        ) {
+            Ok(v) => v,
+            Err(status_code) => {
+                set_status(status, status_code);
+                return std::ptr::null();
+            }
+        };
+        let config_list = match CNativeEnv::c_str_array_to_vec(
+            cnative_env.config_list,
+            cnative_env.num_config_list,
+            "config_list",
+            validate,
+            err_buf,
+        ) {
+            Ok(v) => v,
+            Err(status_code) => {
+                set_status(status, status_code);
+                return std::ptr::null();
+            }
+        };
+
         match stack_limit::with_elastic_stack(
             |stack_limit| -> Result<*const HhasProgram<'static>, anyhow::Error> {
                 let native_env = CNativeEnv::to_compile_env(cnative_env).unwrap();
                 let env = hhbc_by_ref_compile::Env::<&str> {
                     filepath: native_env.filepath.clone(),
-                    config_jsons: vec![],
-                    config_list: vec![],
+                    config_jsons: config_jsons.clone(),
+                    config_list: config_list.clone(),
                     flags: native_env.flags,
                 };
                 let source_text = SourceText::make(RcOc::new(env.filepath.clone()), text);
@@ -170,7 +384,17 @@ unsafe extern "C" fn hackc_compile_hhas_from_text_cpp_ffi(
                     decl_provider(&native_env, &cnative_env),
                 );
                 match compile_result {
-                    Ok((hhas_prog, _)) => Ok(Box::into_raw(Box::new(hhas_prog))),
+                    Ok((hhas_prog, profile)) => {
+                        // Safety: `profile_out` is null or a valid, aligned
+                        // pointer to a `CProfile`.
+                        if let Some(out) = profile_out.as_mut() {
+                            out.parsing_us = to_microsec(profile.parsing_t);
+                            out.codegen_us = to_microsec(profile.codegen_t);
+                            out.printing_us = 0;
+                            out.emitted_bytes = 0;
+                        }
+                        Ok(Box::into_raw(Box::new(hhas_prog)))
+                    }
                     Err(e) => Err(anyhow!("{}", e)),
                 }
             },
@@ -179,40 +403,40 @@ unsafe extern "C" fn hackc_compile_hhas_from_text_cpp_ffi(
         .expect("hackc_compile_hhas_from_text_cpp_ffi: retry failed")
         .map_err(|e| e.to_string())
         {
-            Ok(hhas_prog) => hhas_prog,
+            Ok(hhas_prog) => {
+                set_status(status, CCompileStatus::Success);
+                hhas_prog
+            }
             Err(e) => {
-                if e.len() >= buf.len() {
-                    warn!("Provided error buffer too small.");
+                // Safety: `err_buf.buf` must be valid for reads and writes
+                // of `err_buf.buf_len * mem::size_of::<u8>()` bytes.
+                if err_buf.write(&e) {
+                    set_status(status, CCompileStatus::CompileError);
+                    std::ptr::null()
+                } else {
                     warn!(
-                        "Expected at least {} bytes but got {}.",
-                        e.len() + 1,
-                        buf.len()
+                        "Provided error buffer too small; need {} bytes.",
+                        err_buf.buf_len
                     );
-                } else {
-                    // Safety:
-                    //   - `e` must be valid for reads of `e.len() *
-                    //     size_of::<u8>()` bytes;
-                    //   - `buf` must be valid for writes of of `e.len() *
-                    //     size_of::<u8>()` bytes;
-                    //   - The region of memory beginning at `e` with a
-                    //     size of of `e.len() * size_of::<u8>()` bytes must
-                    //     not overlap with the region of memory beginning
-                    //     at `buf` with the same size;
-                    //   - Even if the of `e.len() * size_of::<u8>()` is
-                    //     `0`, the pointers must be non-null and properly
-                    //     aligned.
-                    std::ptr::copy_nonoverlapping(e.as_ptr(), buf.as_mut_ptr(), e.len());
-                    buf[e.len()] = 0;
+                    set_status(status, CCompileStatus::BufferTooSmall);
+                    std::ptr::null()
                 }
-                std::ptr::null()
             }
         }
     })) {
         Ok(hhas_prog) => hhas_prog,
-        Err(_) => {
+        Err(payload) => {
             if std::env::var_os("HH_TEST_MODE").is_some() {
                 eprintln!("hackc_compile_hhas_from_text_cpp_ffi: panic!");
             }
+            // Safety: `err_buf` is a well aligned, properly initialized
+            // `*mut CErrBuf`.
+            let err_buf: &mut CErrBuf = err_buf.as_mut().unwrap();
+            if err_buf.write(panic_message(&*payload)) {
+                set_status(status, CCompileStatus::InternalPanic);
+            } else {
+                set_status(status, CCompileStatus::BufferTooSmall);
+            }
             std::ptr::null()
         }
     }
@@ -322,7 +546,9 @@ fn print_output(
 unsafe extern "C" fn hackc_hhas_to_string_cpp_ffi(
     cnative_env: *const CNativeEnv,
     prog: *const HhasProgram<'static>,
-    err_buf: *const CErrBuf,
+    err_buf: *mut CErrBuf,
+    status: *mut CCompileStatus,
+    profile_out: *mut CProfile,
 ) -> *const c_char {
     match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         // Safety: `prog`is a well aligned, properly initialized
@@ -332,65 +558,108 @@ unsafe extern "C" fn hackc_hhas_to_string_cpp_ffi(
         // `*const CNativeEnv`.
         let cnative_env = cnative_env.as_ref().unwrap();
 
-        // Safety : `err_buf.buf` must be valid for reads and writes
-        // for `err_buf.buf_len * mem::sizeof::<u8>()` bytes.
-        let buf_len: c_int = (*err_buf).buf_len;
-        let buf: &mut [u8] =
-            std::slice::from_raw_parts_mut((*err_buf).buf as *mut u8, buf_len as usize);
+        // Safety: `err_buf` is a well aligned, properly initialized
+        // `*mut CErrBuf`.
+        let err_buf: &mut CErrBuf = err_buf.as_mut().unwrap();
+
+        let validate = cnative_env.validate_utf8 != 0;
+        if validate {
+            for (field, ptr) in [
+                ("aliased_namespaces", cnative_env.aliased_namespaces),
+                ("include_roots", cnative_env.include_roots),
+            ] {
+                if let Err(status_code) = check_utf8(field, ptr, err_buf) {
+                    set_status(status, status_code);
+                    return std::ptr::null();
+                }
+            }
+        }
+        let config_jsons = match CNativeEnv::c_str_array_to_vec(
+            cnative_env.config_jsons,
+            cnative_env.num_config_jsons,
+            "config_jsons",
+            validate,
+            err_buf,
+        ) {
+            Ok(v) => v,
+            Err(status_code) => {
+                set_status(status, status_code);
+                return std::ptr::null();
+            }
+        };
+        let config_list = match CNativeEnv::c_str_array_to_vec(
+            cnative_env.config_list,
+            cnative_env.num_config_list,
+            "config_list",
+            validate,
+            err_buf,
+        ) {
+            Ok(v) => v,
+            Err(status_code) => {
+                set_status(status, status_code);
+                return std::ptr::null();
+            }
+        };
 
         let native_env: hhbc_by_ref_compile::NativeEnv<&str> =
             CNativeEnv::to_compile_env(cnative_env).unwrap();
         let env = hhbc_by_ref_compile::Env::<&str> {
             filepath: native_env.filepath.clone(),
-            config_jsons: vec![],
-            config_list: vec![],
+            config_jsons,
+            config_list,
             flags: native_env.flags,
         };
         let mut output = String::new();
+        let printing_start = std::time::Instant::now();
         let compile_result =
             hhbc_by_ref_compile::hhas_to_string(&env, Some(&native_env), &mut output, prog);
+        let printing_secs = printing_start.elapsed().as_secs_f64();
         match compile_result {
             Ok(_) => {
+                set_status(status, CCompileStatus::Success);
+                // Safety: `profile_out` is null or a valid, aligned pointer
+                // to a `CProfile`.
+                if let Some(out) = profile_out.as_mut() {
+                    out.parsing_us = 0;
+                    out.codegen_us = 0;
+                    out.printing_us = to_microsec(printing_secs);
+                    out.emitted_bytes = output.len() as u64;
+                }
                 let cs = std::ffi::CString::new(output)
                     .expect("compile_ffi: hackc_hhas_to_string_cpp_ffi: String::new failed");
                 cs.into_raw() as *const c_char
             }
             Err(e) => {
                 let e = e.to_string();
-                if e.len() >= buf.len() {
-                    warn!("Provided error buffer too small.");
+                // Safety: `err_buf.buf` must be valid for reads and writes
+                // of `err_buf.buf_len * mem::size_of::<u8>()` bytes.
+                if err_buf.write(&e) {
+                    set_status(status, CCompileStatus::CompileError);
+                    std::ptr::null::<_>()
+                } else {
                     warn!(
-                        "Expected at least {} bytes but got {}.",
-                        e.len() + 1,
-                        buf.len()
+                        "Provided error buffer too small; need {} bytes.",
+                        err_buf.buf_len
                     );
-                } else {
-                    /*
-                    Safety:
-                      - `e` must be valid for reads of `e.len() *
-                        size_of::<u8>()` bytes;
-                      - `buf` must be valid for writes of of `e.len() *
-                        size_of::<u8>()` bytes;
-                      - The region of memory beginning at `e` with a
-                        size of of `e.len() * size_of::<u8>()` bytes must
-                        not overlap with the region of memory beginning
-                        at `buf` with the same size;
-                      - Even if the of `e.len() * size_of::<u8>()` is
-                        `0`, the pointers must be non-null and properly
-                        aligned.
-                    */
-                    std::ptr::copy_nonoverlapping(e.as_ptr(), buf.as_mut_ptr(), e.len());
-                    buf[e.len()] = 0;
+                    set_status(status, CCompileStatus::BufferTooSmall);
+                    std::ptr::null::<_>()
                 }
-                std::ptr::null::<_>()
             }
         }
     })) {
         Ok(ptr) => ptr,
-        _ => {
+        Err(payload) => {
             if std::env::var_os("HH_TEST_MODE").is_some() {
                 eprintln!("Error: panic in ffi function hackc_hhas_to_string_cpp_ffi");
             }
+            // Safety: `err_buf` is a well aligned, properly initialized
+            // `*mut CErrBuf`.
+            let err_buf: &mut CErrBuf = err_buf.as_mut().unwrap();
+            if err_buf.write(panic_message(&*payload)) {
+                set_status(status, CCompileStatus::InternalPanic);
+            } else {
+                set_status(status, CCompileStatus::BufferTooSmall);
+            }
             std::ptr::null()
         }
     }